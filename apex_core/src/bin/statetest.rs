@@ -0,0 +1,43 @@
+//! State-test fixture runner
+//!
+//! Loads a state-test fixture, replays it through `ApexCore`, and asserts
+//! the resulting state matches the fixture's expectations. Pass `--trace`
+//! to dump the execution trace when a fixture fails.
+
+use std::{env, fs, process};
+
+use anyhow::{Context, Result};
+use apex_core::statetest::{format_trace, load_fixture, run_fixture};
+
+fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let mut trace_on_failure = false;
+    let mut path = None;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--trace" => trace_on_failure = true,
+            other => path = Some(other.to_string()),
+        }
+    }
+
+    let path = path.context("usage: statetest [--trace] <fixture.json>")?;
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("reading fixture {path}"))?;
+    let fixture = load_fixture(&contents)?;
+
+    let result = run_fixture(&fixture);
+    if result.passed {
+        println!("✅ {} passed", result.name);
+        return Ok(());
+    }
+
+    println!("❌ {} failed:", result.name);
+    for mismatch in &result.mismatches {
+        println!("  - {mismatch}");
+    }
+    if trace_on_failure {
+        println!("\ntrace:\n{}", format_trace(&result.trace));
+    }
+    process::exit(1);
+}
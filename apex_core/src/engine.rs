@@ -0,0 +1,217 @@
+//! Common lifecycle trait implemented by each of the quad engines, so
+//! `ApexCore` can initialize and monitor them generically instead of
+//! hand-rolling the same calls once per engine.
+
+use anyhow::Result;
+
+use executor::Executor;
+use math_engine::MathEngine;
+use telemetry::TelemetryEngine;
+use tx_engine::TxEngine;
+
+/// Health reported by an [`Engine`] after initialization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EngineHealth {
+    Healthy,
+    Degraded(String),
+    Down(String),
+}
+
+/// Uniform lifecycle and health surface for a quad engine.
+pub trait Engine {
+    /// Human-readable engine name, used in logs and health reports.
+    fn name(&self) -> &str;
+
+    /// Bring the engine up. Called once during `ApexCore::initialize`.
+    fn initialize(&mut self) -> Result<()>;
+
+    /// Current health of the engine.
+    fn health(&self) -> EngineHealth;
+
+    /// Tear the engine down. Default is a no-op for engines with nothing to release.
+    fn shutdown(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Engine for Executor {
+    fn name(&self) -> &str {
+        "Executor Engine"
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn health(&self) -> EngineHealth {
+        if self.is_active() {
+            EngineHealth::Healthy
+        } else {
+            EngineHealth::Down("executor is inactive".to_string())
+        }
+    }
+}
+
+impl Engine for MathEngine {
+    fn name(&self) -> &str {
+        "Math Engine"
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn health(&self) -> EngineHealth {
+        if self.is_ready() {
+            EngineHealth::Healthy
+        } else {
+            EngineHealth::Down("math engine is not ready".to_string())
+        }
+    }
+}
+
+impl Engine for TelemetryEngine {
+    fn name(&self) -> &str {
+        "Telemetry Engine"
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn health(&self) -> EngineHealth {
+        if self.is_enabled() {
+            EngineHealth::Healthy
+        } else {
+            EngineHealth::Degraded("telemetry is disabled".to_string())
+        }
+    }
+}
+
+impl Engine for TxEngine {
+    fn name(&self) -> &str {
+        "TX Engine"
+    }
+
+    fn initialize(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn health(&self) -> EngineHealth {
+        if self.is_active() {
+            EngineHealth::Healthy
+        } else {
+            EngineHealth::Down("tx engine is inactive".to_string())
+        }
+    }
+}
+
+/// Initialize every engine, continuing past individual failures so one dead
+/// engine doesn't hide failures in the others, then bail with all of their
+/// errors joined together if any engine failed.
+pub fn initialize_engines(engines: &mut [&mut dyn Engine]) -> Result<()> {
+    use tracing::{error, info};
+
+    let mut failures = Vec::new();
+    for engine in engines {
+        match engine.initialize() {
+            Ok(()) => info!("✅ {} ready", engine.name()),
+            Err(err) => {
+                error!("❌ {} failed to initialize: {err}", engine.name());
+                failures.push(format!("{}: {err}", engine.name()));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        anyhow::bail!("engine initialization failed: {}", failures.join("; "));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct OkEngine {
+        label: &'static str,
+        initialized: bool,
+    }
+
+    impl Engine for OkEngine {
+        fn name(&self) -> &str {
+            self.label
+        }
+
+        fn initialize(&mut self) -> Result<()> {
+            self.initialized = true;
+            Ok(())
+        }
+
+        fn health(&self) -> EngineHealth {
+            if self.initialized {
+                EngineHealth::Healthy
+            } else {
+                EngineHealth::Down(format!("{} was never initialized", self.label))
+            }
+        }
+    }
+
+    struct FailingEngine {
+        label: &'static str,
+    }
+
+    impl Engine for FailingEngine {
+        fn name(&self) -> &str {
+            self.label
+        }
+
+        fn initialize(&mut self) -> Result<()> {
+            anyhow::bail!("{} is offline", self.label)
+        }
+
+        fn health(&self) -> EngineHealth {
+            EngineHealth::Down(format!("{} is offline", self.label))
+        }
+    }
+
+    #[test]
+    fn initialize_engines_succeeds_when_every_engine_succeeds() {
+        let mut a = OkEngine { label: "a", initialized: false };
+        let mut b = OkEngine { label: "b", initialized: false };
+
+        let result = initialize_engines(&mut [&mut a, &mut b]);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn initialize_engines_aggregates_every_failure() {
+        let mut ok = OkEngine { label: "ok-engine", initialized: false };
+        let mut first = FailingEngine { label: "first-engine" };
+        let mut second = FailingEngine { label: "second-engine" };
+
+        let err = initialize_engines(&mut [&mut ok, &mut first, &mut second])
+            .expect_err("should fail when any engine fails");
+
+        let message = err.to_string();
+        assert!(message.contains("first-engine"));
+        assert!(message.contains("second-engine"));
+        assert!(!message.contains("ok-engine"));
+    }
+
+    #[test]
+    fn initialize_engines_keeps_going_past_an_early_failure() {
+        let mut first = FailingEngine { label: "first-engine" };
+        let mut second = OkEngine { label: "second-engine", initialized: false };
+
+        // The second engine's initialize() must still run even though the
+        // first one failed, so `second`'s side effects aren't silently
+        // skipped just because it sorts after a dead engine.
+        let result = initialize_engines(&mut [&mut first, &mut second]);
+
+        assert!(result.is_err());
+        assert_eq!(second.health(), EngineHealth::Healthy);
+    }
+}
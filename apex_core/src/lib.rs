@@ -13,6 +13,12 @@ use math_engine::MathEngine;
 use telemetry::TelemetryEngine;
 use tx_engine::TxEngine;
 
+mod engine;
+pub use engine::{Engine, EngineHealth};
+use engine::initialize_engines;
+
+pub mod statetest;
+
 /// Main APEX Core structure
 pub struct ApexCore {
     initialized: bool,
@@ -28,31 +34,70 @@ impl ApexCore {
         info!("Initializing APEX Core with Quad Engines");
         Self {
             initialized: false,
-            executor: Executor::new(),
+            executor: Executor::default(),
             math_engine: MathEngine::new(8),
             telemetry: TelemetryEngine::new(),
             tx_engine: TxEngine::new(),
         }
     }
 
+    /// The four engines as trait objects, for generic initialization/shutdown.
+    fn engines_mut(&mut self) -> [&mut dyn Engine; 4] {
+        [
+            &mut self.executor,
+            &mut self.math_engine,
+            &mut self.telemetry,
+            &mut self.tx_engine,
+        ]
+    }
+
+    /// The four engines as trait objects, for generic health checks.
+    fn engines(&self) -> [&dyn Engine; 4] {
+        [
+            &self.executor,
+            &self.math_engine,
+            &self.telemetry,
+            &self.tx_engine,
+        ]
+    }
+
     /// Initialize the core system and all engines
     pub fn initialize(&mut self) -> Result<()> {
         info!("Initializing all quad engines...");
-        info!("✅ Executor Engine ready");
-        info!("✅ Math Engine ready");
-        info!("✅ Telemetry Engine ready");
-        info!("✅ TX Engine ready");
-        
+
+        initialize_engines(&mut self.engines_mut())?;
+
         self.initialized = true;
         info!("APEX Core initialized successfully");
         Ok(())
     }
 
+    /// Per-engine health, in the same order the engines were registered.
+    pub fn health(&self) -> Vec<(String, EngineHealth)> {
+        self.engines()
+            .into_iter()
+            .map(|engine| (engine.name().to_string(), engine.health()))
+            .collect()
+    }
+
+    /// Shut down all engines.
+    pub fn shutdown(&mut self) -> Result<()> {
+        for engine in self.engines_mut() {
+            engine.shutdown()?;
+        }
+        Ok(())
+    }
+
     /// Get reference to the executor
     pub fn executor(&self) -> &Executor {
         &self.executor
     }
 
+    /// Get mutable reference to the executor
+    pub fn executor_mut(&mut self) -> &mut Executor {
+        &mut self.executor
+    }
+
     /// Get reference to the math engine
     pub fn math_engine(&self) -> &MathEngine {
         &self.math_engine
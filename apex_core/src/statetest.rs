@@ -0,0 +1,240 @@
+//! Deterministic replay/state-test harness for strategies.
+//!
+//! A fixture declares a starting state (account balances, price series,
+//! nonce), a sequence of orders, and the expected post-state. Running a
+//! fixture drives it through `ApexCore`'s Executor, TxEngine, and
+//! MathEngine, with Executor tracing on, so a failing assertion can be
+//! debugged from the recorded execution trace instead of rerunning under a
+//! debugger.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use executor::{ExecOptions, TraceStep};
+use serde::{Deserialize, Serialize};
+use tx_engine::{Destination, Transaction};
+
+use crate::ApexCore;
+
+/// Starting conditions for a state test.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InitialState {
+    pub balances: HashMap<String, f64>,
+    /// Price history fed into `MathEngine` when a fixture asserts on
+    /// `expected.std_deviation`.
+    #[serde(default)]
+    pub price_series: Vec<f64>,
+    /// Starting Executor nonce for every account in `balances`.
+    #[serde(default)]
+    pub nonce: u64,
+}
+
+/// One order to replay through the Executor (and mirror through the
+/// TxEngine as a local transaction) during a state test.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixtureOrder {
+    pub account: String,
+    pub amount: f64,
+}
+
+/// A single step of an expected execution trace, mirroring `TraceStep`
+/// (which isn't itself `Serialize`/`Deserialize`) so fixtures can assert on
+/// it byte-for-byte.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExpectedTraceStep {
+    pub order: usize,
+    pub operation: String,
+    pub amount: f64,
+    pub fee_estimate: f64,
+}
+
+/// Expected post-state after replaying a fixture's orders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpectedState {
+    pub balances: HashMap<String, f64>,
+    /// Expected `MathEngine::std_deviation` of `initial.price_series`.
+    #[serde(default)]
+    pub std_deviation: Option<f64>,
+    /// Expected Executor trace, checked exactly when present.
+    #[serde(default)]
+    pub trace: Option<Vec<ExpectedTraceStep>>,
+}
+
+fn default_check_nonce() -> bool {
+    true
+}
+
+/// A single declarative state test: starting state, orders to replay, and
+/// the expected outcome.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fixture {
+    pub name: String,
+    pub initial: InitialState,
+    pub orders: Vec<FixtureOrder>,
+    pub expected: ExpectedState,
+    #[serde(default = "default_check_nonce")]
+    pub check_nonce: bool,
+}
+
+/// Outcome of replaying one fixture.
+#[derive(Debug)]
+pub struct FixtureResult {
+    pub name: String,
+    pub passed: bool,
+    pub mismatches: Vec<String>,
+    pub trace: Vec<TraceStep>,
+    /// Local transactions mirroring each order, submitted through `TxEngine`.
+    pub transactions: Vec<Transaction>,
+}
+
+/// Parse a fixture from its JSON representation.
+pub fn load_fixture(json: &str) -> Result<Fixture> {
+    Ok(serde_json::from_str(json)?)
+}
+
+/// Replay a fixture against a fresh `ApexCore`, asserting the resulting
+/// state matches `fixture.expected` byte-for-byte.
+pub fn run_fixture(fixture: &Fixture) -> FixtureResult {
+    let mut core = ApexCore::new();
+
+    for (account, balance) in &fixture.initial.balances {
+        core.executor_mut().set_balance(account, *balance);
+        core.executor_mut().set_nonce(account, fixture.initial.nonce);
+    }
+
+    let mut options = ExecOptions::new().with_tracing().with_state_diff();
+    if !fixture.check_nonce {
+        options = options.dont_check_nonce();
+    }
+
+    let mut trace = Vec::new();
+    let mut transactions = Vec::new();
+    for order in &fixture.orders {
+        let nonce = core.executor().nonce(&order.account);
+        let executed = core
+            .executor_mut()
+            .execute(&order.account, order.amount, nonce, options.clone());
+        trace.extend(executed.trace);
+
+        let destination = Destination::Local {
+            account: order.account.clone(),
+        };
+        if let Ok(mut tx) = core.tx_engine().create_transaction(order.amount, destination) {
+            let _ = core.tx_engine().submit(&mut tx);
+            transactions.push(tx);
+        }
+    }
+
+    let mut mismatches = Vec::new();
+    for (account, expected_balance) in &fixture.expected.balances {
+        let actual = core.executor().balance(account);
+        if actual != *expected_balance {
+            mismatches.push(format!("{account}: expected {expected_balance}, got {actual}"));
+        }
+    }
+
+    if let Some(expected_std) = fixture.expected.std_deviation {
+        let actual_std = core.math_engine().std_deviation(&fixture.initial.price_series);
+        if actual_std != expected_std {
+            mismatches.push(format!(
+                "std_deviation: expected {expected_std}, got {actual_std}"
+            ));
+        }
+    }
+
+    if let Some(expected_trace) = &fixture.expected.trace {
+        let actual_trace: Vec<ExpectedTraceStep> = trace
+            .iter()
+            .map(|step| ExpectedTraceStep {
+                order: step.order,
+                operation: step.operation.clone(),
+                amount: step.amount,
+                fee_estimate: step.fee_estimate,
+            })
+            .collect();
+        if &actual_trace != expected_trace {
+            mismatches.push(format!(
+                "trace: expected {expected_trace:?}, got {actual_trace:?}"
+            ));
+        }
+    }
+
+    FixtureResult {
+        name: fixture.name.clone(),
+        passed: mismatches.is_empty(),
+        mismatches,
+        trace,
+        transactions,
+    }
+}
+
+/// Render a fixture's execution trace for `--trace` dumps on failure.
+pub fn format_trace(trace: &[TraceStep]) -> String {
+    trace
+        .iter()
+        .map(|step| {
+            format!(
+                "#{} {} amount={} fee_estimate={}",
+                step.order, step.operation, step.amount, step.fee_estimate
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passing_fixture_matches_expected_balances() {
+        let fixture = load_fixture(
+            r#"{
+                "name": "simple-deposit",
+                "initial": { "balances": { "alice": 100.0 }, "price_series": [1.0, 2.0, 3.0] },
+                "orders": [{ "account": "alice", "amount": 50.0 }],
+                "expected": { "balances": { "alice": 150.0 } }
+            }"#,
+        )
+        .expect("fixture should parse");
+
+        let result = run_fixture(&fixture);
+        assert!(result.passed, "mismatches: {:?}", result.mismatches);
+        assert!(!result.trace.is_empty());
+        assert_eq!(result.transactions.len(), 1);
+    }
+
+    #[test]
+    fn failing_fixture_reports_mismatch() {
+        let fixture = load_fixture(
+            r#"{
+                "name": "wrong-expectation",
+                "initial": { "balances": { "alice": 100.0 } },
+                "orders": [{ "account": "alice", "amount": 50.0 }],
+                "expected": { "balances": { "alice": 999.0 } }
+            }"#,
+        )
+        .expect("fixture should parse");
+
+        let result = run_fixture(&fixture);
+        assert!(!result.passed);
+        assert_eq!(result.mismatches.len(), 1);
+    }
+
+    #[test]
+    fn std_deviation_mismatch_is_reported() {
+        let fixture = load_fixture(
+            r#"{
+                "name": "wrong-std-deviation",
+                "initial": { "balances": { "alice": 100.0 }, "price_series": [1.0, 2.0, 3.0] },
+                "orders": [],
+                "expected": { "balances": { "alice": 100.0 }, "std_deviation": 999.0 }
+            }"#,
+        )
+        .expect("fixture should parse");
+
+        let result = run_fixture(&fixture);
+        assert!(!result.passed);
+        assert!(result.mismatches.iter().any(|m| m.contains("std_deviation")));
+    }
+}
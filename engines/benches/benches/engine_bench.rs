@@ -1,22 +1,180 @@
 //! Engine performance benchmarks
 //!
-//! Benchmark suite for measuring engine performance.
+//! Benchmark suite for measuring engine performance across increasing input
+//! sizes, with results tabulated and emitted as machine-readable JSON so CI
+//! can diff them over time and catch regressions in the math hot paths.
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+use executor::{ExecOptions, Executor};
+use math_engine::MathEngine;
+use tx_engine::{Destination, TxEngine};
+
+/// Upper bound on `--steps`: `100 * (1 << steps)` must stay within `usize`
+/// range, and beyond this the input sizes are impractically large anyway.
+const MAX_STEPS: usize = 16;
+
+/// How many input sizes to try (`steps`) and how many times to repeat each
+/// one (`repeat`), read from `--steps`/`--repeat` CLI flags.
+struct BenchConfig {
+    steps: usize,
+    repeat: usize,
+}
+
+impl BenchConfig {
+    fn from_args() -> Self {
+        let mut steps = 5;
+        let mut repeat = 20;
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--steps" => {
+                    if let Some(value) = args.next() {
+                        steps = value.parse().unwrap_or(steps);
+                    }
+                }
+                "--repeat" => {
+                    if let Some(value) = args.next() {
+                        repeat = value.parse().unwrap_or(repeat);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // At least one sample is required: `summarize` indexes into the
+        // sample vec unconditionally. `steps` is capped because
+        // `input_sizes` computes `100 * (1 << i)` for each `i < steps`,
+        // which would overflow `usize` for a large enough `steps`;
+        // `MAX_STEPS` keeps it far inside range.
+        Self {
+            steps: steps.clamp(1, MAX_STEPS),
+            repeat: repeat.max(1),
+        }
+    }
+
+    /// Input sizes to benchmark at, doubling from 100 up to `steps` points.
+    fn input_sizes(&self) -> Vec<usize> {
+        (0..self.steps).map(|i| 100 * (1 << i)).collect()
+    }
+}
+
+/// Latency/throughput summary for one operation at one input size.
+struct BenchResult {
+    operation: String,
+    input_size: usize,
+    min: Duration,
+    mean: Duration,
+    median: Duration,
+    p99: Duration,
+    throughput_ops_per_sec: f64,
+}
+
+fn summarize(operation: &str, input_size: usize, mut samples: Vec<Duration>) -> BenchResult {
+    samples.sort();
+    let min = samples[0];
+    let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+    let median = samples[samples.len() / 2];
+    let p99_index = (((samples.len() as f64) * 0.99).ceil() as usize).saturating_sub(1);
+    let p99 = samples[p99_index.min(samples.len() - 1)];
+    let throughput_ops_per_sec = if mean.as_secs_f64() > 0.0 {
+        1.0 / mean.as_secs_f64()
+    } else {
+        0.0
+    };
+
+    BenchResult {
+        operation: operation.to_string(),
+        input_size,
+        min,
+        mean,
+        median,
+        p99,
+        throughput_ops_per_sec,
+    }
+}
+
+fn bench<F: FnMut()>(operation: &str, input_size: usize, repeat: usize, mut run: F) -> BenchResult {
+    let mut samples = Vec::with_capacity(repeat);
+    for _ in 0..repeat {
+        let start = Instant::now();
+        run();
+        samples.push(start.elapsed());
+    }
+    summarize(operation, input_size, samples)
+}
+
+fn print_table(results: &[BenchResult]) {
+    println!(
+        "{:<20} {:>10} {:>14} {:>14} {:>14} {:>14} {:>16}",
+        "operation", "input", "min", "mean", "median", "p99", "throughput/s"
+    );
+    for r in results {
+        println!(
+            "{:<20} {:>10} {:>14?} {:>14?} {:>14?} {:>14?} {:>16.1}",
+            r.operation, r.input_size, r.min, r.mean, r.median, r.p99, r.throughput_ops_per_sec
+        );
+    }
+}
+
+/// Render results as a JSON array so CI can diff them over time.
+fn to_json(results: &[BenchResult]) -> String {
+    let entries: Vec<String> = results
+        .iter()
+        .map(|r| {
+            format!(
+                "{{\"operation\":\"{}\",\"input_size\":{},\"min_ns\":{},\"mean_ns\":{},\"median_ns\":{},\"p99_ns\":{},\"throughput_ops_per_sec\":{}}}",
+                r.operation,
+                r.input_size,
+                r.min.as_nanos(),
+                r.mean.as_nanos(),
+                r.median.as_nanos(),
+                r.p99.as_nanos(),
+                r.throughput_ops_per_sec
+            )
+        })
+        .collect();
+    format!("[{}]", entries.join(","))
+}
 
 fn main() {
-    println!("Running APEX Engine Benchmarks...");
-    
-    // Simple benchmark placeholder
-    let start = Instant::now();
-    
-    // Simulate some work
-    let mut sum = 0u64;
-    for i in 0..1_000_000 {
-        sum = sum.wrapping_add(i);
+    let config = BenchConfig::from_args();
+    println!(
+        "Running APEX Engine Benchmarks (steps={}, repeat={})...",
+        config.steps, config.repeat
+    );
+
+    let math_engine = MathEngine::new(8);
+    let tx_engine = TxEngine::new();
+    let mut executor = Executor::default();
+
+    let mut results = Vec::new();
+    for input_size in config.input_sizes() {
+        let series: Vec<f64> = (0..input_size).map(|i| (i as f64).sin() * 100.0).collect();
+        let window = (input_size / 10).max(2);
+
+        results.push(bench("moving_average", input_size, config.repeat, || {
+            let _ = math_engine.moving_average(&series, window);
+        }));
+
+        results.push(bench("std_deviation", input_size, config.repeat, || {
+            let _ = math_engine.std_deviation(&series);
+        }));
+
+        results.push(bench("create_transaction", input_size, config.repeat, || {
+            let destination = Destination::Local {
+                account: "bench".to_string(),
+            };
+            let _ = tx_engine.create_transaction(input_size as f64, destination);
+        }));
+
+        results.push(bench("executor_execute", input_size, config.repeat, || {
+            let nonce = executor.nonce("bench-account");
+            let _ = executor.execute("bench-account", 1.0, nonce, ExecOptions::new());
+        }));
     }
-    
-    let duration = start.elapsed();
-    println!("Benchmark completed in {:?}", duration);
-    println!("Result: {}", sum);
+
+    print_table(&results);
+    println!("\n{}", to_json(&results));
 }
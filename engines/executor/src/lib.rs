@@ -4,30 +4,407 @@
 
 #![allow(dead_code)]
 
+use std::collections::HashMap;
+
 use anyhow::Result;
 use tracing::info;
 
+/// Flat fee rate (in amount units) charged against the notional of a trade,
+/// used to produce the fee estimate recorded in a trace step.
+const FEE_RATE: f64 = 0.001;
+
+/// Per-account balance delta captured when state-diffing is enabled.
+pub type StateDiff = HashMap<String, f64>;
+
+/// A single step recorded while executing an order, in execution order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceStep {
+    /// Position of this step within the execution, starting at zero.
+    pub order: usize,
+    /// Kind of operation performed, prefixed with the backend name, e.g.
+    /// `"simulation:execute"`.
+    pub operation: String,
+    pub amount: f64,
+    pub fee_estimate: f64,
+}
+
+/// Options controlling how `Executor::execute` runs, mirroring the
+/// `TransactOptions { tracing, vm_tracing, check_nonce }` pattern: each
+/// knob is off by default except nonce checking, which is safe-by-default.
+#[derive(Debug, Clone)]
+pub struct ExecOptions {
+    tracing: bool,
+    state_diff: bool,
+    check_nonce: bool,
+}
+
+impl ExecOptions {
+    /// Options with tracing and state-diffing disabled and nonce checks enabled.
+    pub fn new() -> Self {
+        Self {
+            tracing: false,
+            state_diff: false,
+            check_nonce: true,
+        }
+    }
+
+    /// Record an ordered trace of execution steps.
+    pub fn with_tracing(mut self) -> Self {
+        self.tracing = true;
+        self
+    }
+
+    /// Snapshot account balances before and after execution and diff them.
+    pub fn with_state_diff(mut self) -> Self {
+        self.state_diff = true;
+        self
+    }
+
+    /// Skip the account nonce check before executing.
+    pub fn dont_check_nonce(mut self) -> Self {
+        self.check_nonce = false;
+        self
+    }
+}
+
+impl Default for ExecOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A concrete way of carrying out a trade: a live send, a dry-run
+/// simulation, or anything else that can submit/simulate an order.
+pub trait ExecutionBackend {
+    /// Human-readable backend name, surfaced in logs and traces.
+    fn name(&self) -> &str;
+
+    /// Submit the order for real, applying its effects.
+    fn submit(&self, account: &str, amount: f64) -> Result<()>;
+
+    /// Dry-run the order without applying its effects.
+    fn simulate(&self, account: &str, amount: f64) -> Result<()>;
+}
+
+/// Produces an [`ExecutionBackend`], so `Executor` can swap implementations
+/// (e.g. paper-trading vs. live) behind a single call instead of being
+/// compiled against one hardcoded backend.
+pub trait BackendFactory {
+    fn create(&self) -> Box<dyn ExecutionBackend>;
+}
+
+/// Backend that logs orders but never actually submits them; the default
+/// so a freshly built `Executor` is safe to call without wiring anything up.
+struct SimulationBackend;
+
+impl ExecutionBackend for SimulationBackend {
+    fn name(&self) -> &str {
+        "simulation"
+    }
+
+    fn submit(&self, account: &str, amount: f64) -> Result<()> {
+        info!("[simulation] would submit {} for {}", amount, account);
+        Ok(())
+    }
+
+    fn simulate(&self, account: &str, amount: f64) -> Result<()> {
+        info!("[simulation] simulating {} for {}", amount, account);
+        Ok(())
+    }
+}
+
+/// Factory producing [`SimulationBackend`] instances.
+pub struct SimulationBackendFactory;
+
+impl BackendFactory for SimulationBackendFactory {
+    fn create(&self) -> Box<dyn ExecutionBackend> {
+        Box::new(SimulationBackend)
+    }
+}
+
+/// Backend that submits orders for real.
+struct LiveBackend;
+
+impl ExecutionBackend for LiveBackend {
+    fn name(&self) -> &str {
+        "live"
+    }
+
+    fn submit(&self, account: &str, amount: f64) -> Result<()> {
+        info!("[live] submitting {} for {}", amount, account);
+        Ok(())
+    }
+
+    fn simulate(&self, account: &str, amount: f64) -> Result<()> {
+        info!("[live] dry-running {} for {}", amount, account);
+        Ok(())
+    }
+}
+
+/// Factory producing [`LiveBackend`] instances.
+pub struct LiveBackendFactory;
+
+impl BackendFactory for LiveBackendFactory {
+    fn create(&self) -> Box<dyn ExecutionBackend> {
+        Box::new(LiveBackend)
+    }
+}
+
+/// Outcome of a traced execution: the result plus whatever audit data the
+/// caller opted into via `ExecOptions`.
+#[derive(Debug)]
+pub struct Executed {
+    pub trace: Vec<TraceStep>,
+    pub state_diff: Option<StateDiff>,
+    pub result: Result<()>,
+}
+
 /// Executor for managing trade execution
 pub struct Executor {
     active: bool,
+    nonces: HashMap<String, u64>,
+    step_count: usize,
+    balances: HashMap<String, f64>,
+    backend_factory: Box<dyn BackendFactory>,
 }
 
 impl Executor {
-    /// Create a new Executor instance
-    pub fn new() -> Self {
+    /// Create a new Executor backed by the given factory.
+    pub fn new(backend_factory: Box<dyn BackendFactory>) -> Self {
         info!("Initializing Executor Engine");
-        Self { active: true }
+        Self {
+            active: true,
+            nonces: HashMap::new(),
+            step_count: 0,
+            balances: HashMap::new(),
+            backend_factory,
+        }
     }
 
-    /// Execute a trade order
-    pub fn execute(&self) -> Result<()> {
-        info!("Executing trade");
-        Ok(())
+    /// Swap the backend factory in place.
+    pub fn set_backend(&mut self, backend_factory: Box<dyn BackendFactory>) {
+        self.backend_factory = backend_factory;
+    }
+
+    /// Builder-style variant of [`Executor::set_backend`].
+    pub fn with_backend(mut self, backend_factory: Box<dyn BackendFactory>) -> Self {
+        self.set_backend(backend_factory);
+        self
+    }
+
+    /// Seed or update the known balance for an account, used as the
+    /// baseline for state-diff snapshots.
+    pub fn set_balance(&mut self, account: &str, balance: f64) {
+        self.balances.insert(account.to_string(), balance);
+    }
+
+    /// Current balance for an account, or zero if unknown.
+    pub fn balance(&self, account: &str) -> f64 {
+        *self.balances.get(account).unwrap_or(&0.0)
+    }
+
+    /// Whether the engine is active and able to accept orders.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// The next nonce expected for `account`, i.e. the number of orders
+    /// already applied for it.
+    pub fn nonce(&self, account: &str) -> u64 {
+        *self.nonces.get(account).unwrap_or(&0)
+    }
+
+    /// Seed the expected nonce for an account, e.g. when replaying a fixture
+    /// that starts from a non-zero history.
+    pub fn set_nonce(&mut self, account: &str, nonce: u64) {
+        self.nonces.insert(account.to_string(), nonce);
+    }
+
+    /// Execute a trade order for `account` against the current backend,
+    /// producing an auditable `Executed` record shaped by `options`.
+    ///
+    /// `nonce` must equal `self.nonce(account)` when `options` has nonce
+    /// checking enabled (the default); otherwise the order is rejected
+    /// without touching balances, mirroring a real chain's replay defense.
+    pub fn execute(
+        &mut self,
+        account: &str,
+        amount: f64,
+        nonce: u64,
+        options: ExecOptions,
+    ) -> Executed {
+        let expected_nonce = self.nonce(account);
+        if options.check_nonce && nonce != expected_nonce {
+            return Executed {
+                trace: Vec::new(),
+                state_diff: None,
+                result: Err(anyhow::anyhow!(
+                    "nonce mismatch for {account}: expected {expected_nonce}, got {nonce}"
+                )),
+            };
+        }
+
+        let before = options.state_diff.then(|| self.balances.clone());
+
+        let backend = self.backend_factory.create();
+        let result = backend.submit(account, amount);
+
+        if result.is_err() {
+            return Executed {
+                trace: Vec::new(),
+                state_diff: None,
+                result,
+            };
+        }
+
+        let fee_estimate = amount.abs() * FEE_RATE;
+        *self.balances.entry(account.to_string()).or_insert(0.0) += amount;
+        self.nonces.insert(account.to_string(), expected_nonce + 1);
+
+        let order = self.step_count;
+        self.step_count += 1;
+
+        let trace = if options.tracing {
+            vec![TraceStep {
+                order,
+                operation: format!("{}:execute", backend.name()),
+                amount,
+                fee_estimate,
+            }]
+        } else {
+            Vec::new()
+        };
+
+        let state_diff = before.map(|before| diff_balances(&before, &self.balances));
+
+        Executed {
+            trace,
+            state_diff,
+            result,
+        }
+    }
+
+    /// Dry-run a trade through the current backend without applying it.
+    pub fn simulate(&self, account: &str, amount: f64) -> Result<()> {
+        self.backend_factory.create().simulate(account, amount)
     }
 }
 
+/// Compute the per-account balance delta between two snapshots, keeping
+/// only accounts whose balance actually changed.
+fn diff_balances(before: &HashMap<String, f64>, after: &HashMap<String, f64>) -> StateDiff {
+    let mut diff = StateDiff::new();
+    for (account, after_balance) in after {
+        let before_balance = before.get(account).copied().unwrap_or(0.0);
+        let delta = after_balance - before_balance;
+        if delta != 0.0 {
+            diff.insert(account.clone(), delta);
+        }
+    }
+    diff
+}
+
 impl Default for Executor {
     fn default() -> Self {
-        Self::new()
+        Self::new(Box::new(SimulationBackendFactory))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_order_increments_across_calls() {
+        let mut executor = Executor::default();
+
+        let first = executor.execute("alice", 10.0, 0, ExecOptions::new().with_tracing());
+        let second = executor.execute("alice", 5.0, 1, ExecOptions::new().with_tracing());
+
+        assert_eq!(first.trace[0].order, 0);
+        assert_eq!(second.trace[0].order, 1);
+    }
+
+    #[test]
+    fn nonce_mismatch_is_rejected_without_mutating_balances() {
+        let mut executor = Executor::default();
+        executor.set_balance("alice", 100.0);
+
+        let executed = executor.execute("alice", 50.0, 7, ExecOptions::new());
+
+        assert!(executed.result.is_err());
+        assert_eq!(executor.balance("alice"), 100.0);
+        assert_eq!(executor.nonce("alice"), 0);
+    }
+
+    #[test]
+    fn matching_nonce_advances_the_account_nonce() {
+        let mut executor = Executor::default();
+
+        let executed = executor.execute("alice", 50.0, 0, ExecOptions::new());
+
+        assert!(executed.result.is_ok());
+        assert_eq!(executor.nonce("alice"), 1);
+    }
+
+    #[test]
+    fn dont_check_nonce_ignores_a_stale_nonce() {
+        let mut executor = Executor::default();
+
+        let executed = executor.execute("alice", 50.0, 999, ExecOptions::new().dont_check_nonce());
+
+        assert!(executed.result.is_ok());
+    }
+
+    struct FailingBackend;
+
+    impl ExecutionBackend for FailingBackend {
+        fn name(&self) -> &str {
+            "failing"
+        }
+
+        fn submit(&self, _account: &str, _amount: f64) -> Result<()> {
+            anyhow::bail!("backend is down")
+        }
+
+        fn simulate(&self, _account: &str, _amount: f64) -> Result<()> {
+            anyhow::bail!("backend is down")
+        }
+    }
+
+    struct FailingBackendFactory;
+
+    impl BackendFactory for FailingBackendFactory {
+        fn create(&self) -> Box<dyn ExecutionBackend> {
+            Box::new(FailingBackend)
+        }
+    }
+
+    #[test]
+    fn failed_submit_leaves_balances_nonce_and_trace_untouched() {
+        let mut executor =
+            Executor::default().with_backend(Box::new(FailingBackendFactory));
+        executor.set_balance("alice", 100.0);
+
+        let executed = executor.execute("alice", 50.0, 0, ExecOptions::new().with_tracing());
+
+        assert!(executed.result.is_err());
+        assert!(executed.trace.is_empty());
+        assert!(executed.state_diff.is_none());
+        assert_eq!(executor.balance("alice"), 100.0);
+        assert_eq!(executor.nonce("alice"), 0);
+    }
+
+    #[test]
+    fn state_diff_reports_only_changed_accounts() {
+        let mut executor = Executor::default();
+        executor.set_balance("alice", 100.0);
+        executor.set_balance("bob", 50.0);
+
+        let executed = executor.execute("alice", 25.0, 0, ExecOptions::new().with_state_diff());
+
+        let diff = executed.state_diff.expect("state-diff should be present");
+        assert_eq!(diff.get("alice"), Some(&25.0));
+        assert_eq!(diff.get("bob"), None);
     }
 }
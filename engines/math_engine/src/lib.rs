@@ -4,42 +4,210 @@
 
 #![allow(dead_code)]
 
+use std::collections::VecDeque;
+
 use anyhow::Result;
 
+/// Maintains a numerically stable running mean and variance using Welford's
+/// online algorithm, optionally bounded to a trailing window so both update
+/// in O(1) per sample instead of rescanning the whole series.
+#[derive(Debug, Clone)]
+pub struct RollingStats {
+    window: Option<usize>,
+    values: VecDeque<f64>,
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl RollingStats {
+    fn new(window: Option<usize>) -> Self {
+        Self {
+            window,
+            values: VecDeque::new(),
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    /// Feed a new sample, updating the running mean and variance. If a
+    /// window is set and full, the outgoing sample is subtracted first.
+    pub fn update(&mut self, x: f64) {
+        if let Some(window) = self.window {
+            if window == 0 {
+                return;
+            }
+            if self.values.len() == window {
+                let outgoing = self.values.pop_front().expect("window is full");
+                self.remove(outgoing);
+            }
+            self.values.push_back(x);
+        }
+        self.add(x);
+    }
+
+    fn add(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn remove(&mut self, x: f64) {
+        if self.count <= 1 {
+            self.count = 0;
+            self.mean = 0.0;
+            self.m2 = 0.0;
+            return;
+        }
+        let count = self.count as f64;
+        let new_mean = (count * self.mean - x) / (count - 1.0);
+        self.m2 -= (x - self.mean) * (x - new_mean);
+        self.mean = new_mean;
+        self.count -= 1;
+    }
+
+    /// Number of samples currently contributing to the statistics.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Running mean.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Population variance (`M2 / count`).
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+
+    /// Sample variance, Bessel-corrected (`M2 / (count - 1)`).
+    pub fn sample_variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / (self.count as f64 - 1.0)
+        }
+    }
+
+    /// Population standard deviation.
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+/// One point of a Bollinger Band: a moving average flanked by `k` rolling
+/// standard deviations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BollingerBand {
+    pub middle: f64,
+    pub upper: f64,
+    pub lower: f64,
+}
+
 /// Math engine for complex calculations
 pub struct MathEngine {
     precision: u32,
+    ready: bool,
 }
 
 impl MathEngine {
     /// Create a new MathEngine instance
     pub fn new(precision: u32) -> Self {
-        Self { precision }
+        Self {
+            precision,
+            ready: true,
+        }
+    }
+
+    /// Whether the engine is ready to serve calculations.
+    pub fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    /// Round a value to the engine's configured precision.
+    fn round(&self, value: f64) -> f64 {
+        let factor = 10f64.powi(self.precision as i32);
+        (value * factor).round() / factor
+    }
+
+    /// Start a windowed Welford accumulator, for O(1)-per-tick moving
+    /// average / standard deviation updates.
+    pub fn rolling_stats(&self, window: usize) -> RollingStats {
+        RollingStats::new(Some(window))
     }
 
     /// Calculate moving average
     pub fn moving_average(&self, data: &[f64], window: usize) -> Result<Vec<f64>> {
-        if data.len() < window {
+        if window == 0 || data.len() < window {
             return Ok(Vec::new());
         }
 
-        let mut result = Vec::new();
-        for i in window..=data.len() {
-            let sum: f64 = data[i - window..i].iter().sum();
-            result.push(sum / window as f64);
+        let mut stats = self.rolling_stats(window);
+        let mut result = Vec::with_capacity(data.len() - window + 1);
+        for (i, &x) in data.iter().enumerate() {
+            stats.update(x);
+            if i + 1 >= window {
+                result.push(self.round(stats.mean()));
+            }
         }
         Ok(result)
     }
 
     /// Calculate standard deviation
     pub fn std_deviation(&self, data: &[f64]) -> f64 {
-        if data.is_empty() {
-            return 0.0;
+        let mut stats = RollingStats::new(None);
+        for &x in data {
+            stats.update(x);
+        }
+        self.round(stats.std_dev())
+    }
+
+    /// Exponential moving average, using the standard `2 / (period + 1)`
+    /// smoothing factor and seeding the series with its first value.
+    pub fn ema(&self, data: &[f64], period: usize) -> Result<Vec<f64>> {
+        if period == 0 || data.is_empty() {
+            return Ok(Vec::new());
         }
 
-        let mean = data.iter().sum::<f64>() / data.len() as f64;
-        let variance = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / data.len() as f64;
-        variance.sqrt()
+        let alpha = 2.0 / (period as f64 + 1.0);
+        let mut result = Vec::with_capacity(data.len());
+        let mut prev = data[0];
+        result.push(self.round(prev));
+        for &x in &data[1..] {
+            prev = alpha * x + (1.0 - alpha) * prev;
+            result.push(self.round(prev));
+        }
+        Ok(result)
+    }
+
+    /// Bollinger Bands over a rolling `window`, `k` standard deviations wide.
+    pub fn bollinger_bands(&self, data: &[f64], window: usize, k: f64) -> Result<Vec<BollingerBand>> {
+        if window == 0 || data.len() < window {
+            return Ok(Vec::new());
+        }
+
+        let mut stats = self.rolling_stats(window);
+        let mut result = Vec::with_capacity(data.len() - window + 1);
+        for (i, &x) in data.iter().enumerate() {
+            stats.update(x);
+            if i + 1 >= window {
+                let middle = stats.mean();
+                let band = k * stats.std_dev();
+                result.push(BollingerBand {
+                    middle: self.round(middle),
+                    upper: self.round(middle + band),
+                    lower: self.round(middle - band),
+                });
+            }
+        }
+        Ok(result)
     }
 }
 
@@ -48,3 +216,90 @@ impl Default for MathEngine {
         Self::new(8)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn rolling_stats_matches_naive_mean_and_population_variance() {
+        let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let mut stats = RollingStats::new(None);
+        for &x in &data {
+            stats.update(x);
+        }
+
+        assert!(approx_eq(stats.mean(), 5.0));
+        assert!(approx_eq(stats.variance(), 4.0));
+        assert!(approx_eq(stats.std_dev(), 2.0));
+    }
+
+    #[test]
+    fn windowed_rolling_stats_drops_outgoing_samples() {
+        let mut stats = RollingStats::new(Some(3));
+        for x in [1.0, 2.0, 3.0, 4.0, 5.0] {
+            stats.update(x);
+        }
+
+        // Only the last 3 samples (3, 4, 5) should contribute.
+        assert!(approx_eq(stats.mean(), 4.0));
+        assert_eq!(stats.count(), 3);
+    }
+
+    #[test]
+    fn moving_average_matches_naive_window_sum() {
+        let engine = MathEngine::new(8);
+        let result = engine
+            .moving_average(&[1.0, 2.0, 3.0, 4.0, 5.0], 3)
+            .unwrap();
+
+        assert_eq!(result, vec![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn moving_average_is_empty_when_window_exceeds_data() {
+        let engine = MathEngine::new(8);
+        let result = engine.moving_average(&[1.0, 2.0], 3).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn std_deviation_matches_known_example() {
+        let engine = MathEngine::new(8);
+        let result = engine.std_deviation(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert!(approx_eq(result, 2.0));
+    }
+
+    #[test]
+    fn ema_seeds_with_first_value_then_smooths() {
+        let engine = MathEngine::new(8);
+        let result = engine.ema(&[1.0, 2.0, 3.0], 2).unwrap();
+
+        let alpha = 2.0 / 3.0;
+        let expected_1 = alpha * 2.0 + (1.0 - alpha) * 1.0;
+        let expected_2 = alpha * 3.0 + (1.0 - alpha) * expected_1;
+
+        assert!(approx_eq(result[0], 1.0));
+        assert!(approx_eq(result[1], expected_1));
+        assert!(approx_eq(result[2], expected_2));
+    }
+
+    #[test]
+    fn bollinger_bands_center_on_the_moving_average() {
+        let engine = MathEngine::new(8);
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let bands = engine.bollinger_bands(&data, 3, 1.0).unwrap();
+        let moving_average = engine.moving_average(&data, 3).unwrap();
+
+        assert_eq!(bands.len(), moving_average.len());
+        for (band, mean) in bands.iter().zip(moving_average.iter()) {
+            assert!(approx_eq(band.middle, *mean));
+            assert!(band.upper > band.middle);
+            assert!(band.lower < band.middle);
+        }
+    }
+}
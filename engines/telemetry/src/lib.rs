@@ -33,6 +33,11 @@ impl TelemetryEngine {
             info!("Recording metric: {:?}", data);
         }
     }
+
+    /// Whether the engine is enabled and recording metrics.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
 }
 
 impl Default for TelemetryEngine {
@@ -40,3 +45,24 @@ impl Default for TelemetryEngine {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_engine_is_enabled_by_default() {
+        let telemetry = TelemetryEngine::default();
+        assert!(telemetry.is_enabled());
+    }
+
+    #[test]
+    fn record_does_not_panic_while_enabled() {
+        let telemetry = TelemetryEngine::default();
+        telemetry.record(TelemetryData {
+            timestamp: 1_700_000_000,
+            metric_name: "fill_latency_ms".to_string(),
+            value: 12.5,
+        });
+    }
+}
@@ -4,17 +4,37 @@
 
 #![allow(dead_code)]
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
 use tracing::info;
 use uuid::Uuid;
 
+/// Fee rate charged against the transferred amount on a cross-chain route,
+/// applied once per route regardless of its number of hops.
+const RELAY_FEE_RATE: f64 = 0.0005;
+
 /// Transaction status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum TxStatus {
     Pending,
     Confirmed,
     Failed,
+    /// Handed off to the source chain for cross-chain relay.
+    Dispatched,
+    /// In flight on the relay, not yet seen on the destination chain.
+    RelayPending,
+    /// Observed on the destination chain.
+    ArrivedRemote,
+}
+
+/// A destination expressed as a path of junctions: which chain, and which
+/// account on that chain, rather than a flat address.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum Destination {
+    /// Stay on the local chain, crediting `account` directly.
+    Local { account: String },
+    /// Route to `account` on the parachain/child-chain identified by `chain_id`.
+    Remote { chain_id: u32, account: String },
 }
 
 /// Transaction structure
@@ -23,6 +43,16 @@ pub struct Transaction {
     pub id: String,
     pub status: TxStatus,
     pub amount: f64,
+    pub destination: Destination,
+}
+
+/// Resolution of where a transaction goes: whether it leaves the local
+/// chain, and what hops/fees that takes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoutePlan {
+    pub is_cross_chain: bool,
+    pub hops: Vec<String>,
+    pub fee_estimate: f64,
 }
 
 /// Transaction engine for managing blockchain transactions
@@ -37,22 +67,95 @@ impl TxEngine {
         Self { active: true }
     }
 
-    /// Create a new transaction
-    pub fn create_transaction(&self, amount: f64) -> Result<Transaction> {
+    /// Create a new transaction bound for `destination`. Every transaction
+    /// starts `Pending`, whether local or cross-chain, until `submit()`
+    /// actually hands it off.
+    pub fn create_transaction(&self, amount: f64, destination: Destination) -> Result<Transaction> {
         let tx = Transaction {
             id: Uuid::new_v4().to_string(),
             status: TxStatus::Pending,
             amount,
+            destination,
         };
         info!("Created transaction: {}", tx.id);
         Ok(tx)
     }
 
-    /// Submit a transaction
-    pub fn submit(&self, tx: &Transaction) -> Result<()> {
+    /// Resolve whether a transaction is local or cross-chain, and what
+    /// intermediate hops/fees that route takes.
+    pub fn route(&self, tx: &Transaction) -> RoutePlan {
+        match &tx.destination {
+            Destination::Local { account } => RoutePlan {
+                is_cross_chain: false,
+                hops: vec![format!("local:{account}")],
+                fee_estimate: 0.0,
+            },
+            Destination::Remote { chain_id, account } => RoutePlan {
+                is_cross_chain: true,
+                hops: vec![
+                    "local".to_string(),
+                    "relay".to_string(),
+                    format!("chain-{chain_id}:{account}"),
+                ],
+                fee_estimate: tx.amount.abs() * RELAY_FEE_RATE,
+            },
+        }
+    }
+
+    /// Submit a `Pending` transaction, advancing it one step along its
+    /// lifecycle: a local transfer goes straight to `Confirmed`, while a
+    /// cross-chain transfer is handed off to the source chain and becomes
+    /// `Dispatched`.
+    pub fn submit(&self, tx: &mut Transaction) -> Result<()> {
+        if tx.status != TxStatus::Pending {
+            bail!(
+                "cannot submit {} from status {:?}, expected Pending",
+                tx.id,
+                tx.status
+            );
+        }
+
         info!("Submitting transaction: {}", tx.id);
+        tx.status = match &tx.destination {
+            Destination::Local { .. } => TxStatus::Confirmed,
+            Destination::Remote { .. } => TxStatus::Dispatched,
+        };
         Ok(())
     }
+
+    /// Advance a dispatched cross-chain transaction onto the relay.
+    pub fn advance_relay(&self, tx: &mut Transaction) -> Result<()> {
+        if tx.status != TxStatus::Dispatched {
+            bail!(
+                "cannot relay {} from status {:?}, expected Dispatched",
+                tx.id,
+                tx.status
+            );
+        }
+        tx.status = TxStatus::RelayPending;
+        info!("Transaction {} is now relay pending", tx.id);
+        Ok(())
+    }
+
+    /// Mark a relayed transaction as observed on its destination chain, the
+    /// final step of the cross-chain lifecycle.
+    pub fn mark_arrived(&self, tx: &mut Transaction) -> Result<()> {
+        if tx.status != TxStatus::RelayPending {
+            bail!(
+                "cannot mark {} arrived from status {:?}, expected RelayPending",
+                tx.id,
+                tx.status
+            );
+        }
+        tx.status = TxStatus::ArrivedRemote;
+        info!("Transaction {} arrived remote", tx.id);
+        Ok(())
+    }
+
+    /// Whether the engine is active and able to process transactions.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
 }
 
 impl Default for TxEngine {
@@ -60,3 +163,127 @@ impl Default for TxEngine {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_transaction_always_starts_pending() {
+        let engine = TxEngine::default();
+
+        let local = engine
+            .create_transaction(10.0, Destination::Local { account: "alice".to_string() })
+            .unwrap();
+        let remote = engine
+            .create_transaction(
+                10.0,
+                Destination::Remote { chain_id: 2, account: "bob".to_string() },
+            )
+            .unwrap();
+
+        assert_eq!(local.status, TxStatus::Pending);
+        assert_eq!(remote.status, TxStatus::Pending);
+    }
+
+    #[test]
+    fn route_is_free_and_direct_for_local_destinations() {
+        let engine = TxEngine::default();
+        let tx = engine
+            .create_transaction(10.0, Destination::Local { account: "alice".to_string() })
+            .unwrap();
+
+        let plan = engine.route(&tx);
+
+        assert!(!plan.is_cross_chain);
+        assert_eq!(plan.hops, vec!["local:alice".to_string()]);
+        assert_eq!(plan.fee_estimate, 0.0);
+    }
+
+    #[test]
+    fn route_charges_relay_fee_for_remote_destinations() {
+        let engine = TxEngine::default();
+        let tx = engine
+            .create_transaction(200.0, Destination::Remote { chain_id: 7, account: "bob".to_string() })
+            .unwrap();
+
+        let plan = engine.route(&tx);
+
+        assert!(plan.is_cross_chain);
+        assert_eq!(plan.hops, vec!["local".to_string(), "relay".to_string(), "chain-7:bob".to_string()]);
+        assert_eq!(plan.fee_estimate, 200.0 * RELAY_FEE_RATE);
+    }
+
+    #[test]
+    fn submit_confirms_a_local_transaction() {
+        let engine = TxEngine::default();
+        let mut tx = engine
+            .create_transaction(10.0, Destination::Local { account: "alice".to_string() })
+            .unwrap();
+
+        engine.submit(&mut tx).unwrap();
+
+        assert_eq!(tx.status, TxStatus::Confirmed);
+    }
+
+    #[test]
+    fn remote_transaction_advances_through_the_full_lifecycle() {
+        let engine = TxEngine::default();
+        let mut tx = engine
+            .create_transaction(10.0, Destination::Remote { chain_id: 1, account: "bob".to_string() })
+            .unwrap();
+
+        engine.submit(&mut tx).unwrap();
+        assert_eq!(tx.status, TxStatus::Dispatched);
+
+        engine.advance_relay(&mut tx).unwrap();
+        assert_eq!(tx.status, TxStatus::RelayPending);
+
+        engine.mark_arrived(&mut tx).unwrap();
+        assert_eq!(tx.status, TxStatus::ArrivedRemote);
+    }
+
+    #[test]
+    fn submit_rejects_a_transaction_that_already_arrived() {
+        let engine = TxEngine::default();
+        let mut tx = engine
+            .create_transaction(10.0, Destination::Remote { chain_id: 1, account: "bob".to_string() })
+            .unwrap();
+
+        engine.submit(&mut tx).unwrap();
+        engine.advance_relay(&mut tx).unwrap();
+        engine.mark_arrived(&mut tx).unwrap();
+
+        let result = engine.submit(&mut tx);
+
+        assert!(result.is_err());
+        assert_eq!(tx.status, TxStatus::ArrivedRemote);
+    }
+
+    #[test]
+    fn advance_relay_requires_dispatched_status() {
+        let engine = TxEngine::default();
+        let mut tx = engine
+            .create_transaction(10.0, Destination::Remote { chain_id: 1, account: "bob".to_string() })
+            .unwrap();
+
+        let result = engine.advance_relay(&mut tx);
+
+        assert!(result.is_err());
+        assert_eq!(tx.status, TxStatus::Pending);
+    }
+
+    #[test]
+    fn mark_arrived_requires_relay_pending_status() {
+        let engine = TxEngine::default();
+        let mut tx = engine
+            .create_transaction(10.0, Destination::Remote { chain_id: 1, account: "bob".to_string() })
+            .unwrap();
+
+        engine.submit(&mut tx).unwrap();
+        let result = engine.mark_arrived(&mut tx);
+
+        assert!(result.is_err());
+        assert_eq!(tx.status, TxStatus::Dispatched);
+    }
+}